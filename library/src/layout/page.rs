@@ -10,19 +10,63 @@ pub struct PageNode(pub Content);
 
 #[node]
 impl PageNode {
-    /// The unflipped width of the page.
-    #[property(resolve)]
-    pub const WIDTH: Smart<Length> = Smart::Custom(Paper::A4.width().into());
-    /// The unflipped height of the page.
-    #[property(resolve)]
-    pub const HEIGHT: Smart<Length> = Smart::Custom(Paper::A4.height().into());
-    /// Whether the page is flipped into landscape orientation.
-    pub const FLIPPED: bool = false;
+    /// The page's width before `orientation` is applied.
+    ///
+    /// Left unset by default, which asks [`PageNode::layout`] to resolve
+    /// its locale's preferred paper instead of hard-coding A4. This is
+    /// distinct from an explicit `{auto}`, which instead fits the page to
+    /// its content along this axis.
+    pub const WIDTH: PageDimension = PageDimension::Unset;
+    /// The page's height before `orientation` is applied. See `width`.
+    pub const HEIGHT: PageDimension = PageDimension::Unset;
+
+    /// The page's orientation.
+    ///
+    /// By default, pages are `{portrait}`. Setting this to `{landscape}`
+    /// swaps `width` and `height` at layout time, even when both came from
+    /// `paper` in the same `#set page` call (`paper` always resolves to its
+    /// portrait dimensions), so `#set page(paper: "a4", orientation:
+    /// landscape)` works in one declaration. Since a page run only applies
+    /// to the content after it, a `#set page(orientation: landscape)` after
+    /// a `pagebreak` affects only the following pages, enabling documents
+    /// that mix a landscape table into an otherwise portrait report.
+    pub const ORIENTATION: Orientation = Orientation::Portrait;
+
+    /// The predefined paper the page matches, if any: the one given as
+    /// `paper`, or else the one nearest to an explicit `width` and `height`
+    /// set together (see [`Paper::nearest`]). Its name is available via
+    /// [`Paper::name`]. Header and footer functions receive only the page
+    /// number, not this property.
+    pub const PAPER: Option<Paper> = None;
 
     /// The page's margins.
     #[property(fold)]
     pub const MARGINS: Sides<Option<Smart<Rel<Length>>>> = Sides::splat(Smart::Auto);
 
+    /// The page's margin on the side of the binding, overriding whichever of
+    /// `left`/`right` that turns out to be for a given page once `two-sided`
+    /// is enabled.
+    pub const INSIDE: Option<Smart<Rel<Length>>> = None;
+    /// The page's margin on the side away from the binding, overriding
+    /// whichever of `left`/`right` that turns out to be, analogously to
+    /// `inside`.
+    pub const OUTSIDE: Option<Smart<Rel<Length>>> = None;
+
+    /// Whether recto (odd) and verso (even) pages mirror their `inside`/
+    /// `outside` margins around the binding instead of using identical
+    /// margins on every page, as is common for double-sided, book-style
+    /// printing.
+    pub const TWO_SIDED: bool = false;
+
+    /// Which side the binding is on, used to resolve `inside`/`outside`
+    /// once `two-sided` is enabled.
+    ///
+    /// - `{auto}`: `left` for left-to-right text, `right` for right-to-left
+    ///   text.
+    /// - `left`: Bound on the left, as is common for left-to-right books.
+    /// - `right`: Bound on the right, as is common for right-to-left books.
+    pub const BINDING: Smart<Binding> = Smart::Auto;
+
     /// How many columns the page has.
     pub const COLUMNS: NonZeroUsize = NonZeroUsize::new(1).unwrap();
     /// The page's background color.
@@ -47,8 +91,17 @@ impl PageNode {
 
     fn set(...) {
         if let Some(paper) = args.named_or_find::<Paper>("paper")? {
-            styles.set(Self::WIDTH, Smart::Custom(paper.width().into()));
-            styles.set(Self::HEIGHT, Smart::Custom(paper.height().into()));
+            let short = paper.width().min(paper.height());
+            let long = paper.width().max(paper.height());
+            styles.set(Self::WIDTH, PageDimension::Length(short.into()));
+            styles.set(Self::HEIGHT, PageDimension::Length(long.into()));
+            styles.set(Self::PAPER, Some(paper));
+        } else if let (Some(PageDimension::Length(width)), Some(PageDimension::Length(height))) =
+            (args.named::<PageDimension>("width")?, args.named::<PageDimension>("height")?)
+        {
+            // No named paper was given, but record which predefined paper
+            // the explicit size is closest to, if any.
+            styles.set(Self::PAPER, Paper::nearest(width.abs, height.abs));
         }
     }
 }
@@ -61,12 +114,23 @@ impl PageNode {
         mut page: usize,
         styles: StyleChain,
     ) -> SourceResult<Vec<Frame>> {
-        // When one of the lengths is infinite the page fits its content along
-        // that axis.
-        let width = styles.get(Self::WIDTH).unwrap_or(Abs::inf());
-        let height = styles.get(Self::HEIGHT).unwrap_or(Abs::inf());
+        // `Unset` (the default when `width`/`height` were never set at
+        // all) asks the compiler host for its locale's preferred paper,
+        // rather than reading the process environment here directly, so
+        // compilation stays deterministic and sandboxed unless the host
+        // explicitly opts in; an explicit `auto` instead fits the page to
+        // its content along that axis, same as before locale-aware
+        // defaults existed.
+        let system_default = world.default_paper();
+        let resolve_dimension = |dimension, fallback: Abs| match dimension {
+            PageDimension::Unset => fallback,
+            PageDimension::Auto => Abs::inf(),
+            PageDimension::Length(length) => length.resolve(styles),
+        };
+        let width = resolve_dimension(styles.get(Self::WIDTH), system_default.width());
+        let height = resolve_dimension(styles.get(Self::HEIGHT), system_default.height());
         let mut size = Size::new(width, height);
-        if styles.get(Self::FLIPPED) {
+        if styles.get(Self::ORIENTATION) == Orientation::Landscape {
             std::mem::swap(&mut size.x, &mut size.y);
         }
 
@@ -77,7 +141,38 @@ impl PageNode {
 
         // Determine the margins.
         let default = Rel::from(0.1190 * min);
-        let padding = styles.get(Self::MARGINS).map(|side| side.unwrap_or(default));
+        let margins = styles.get(Self::MARGINS).map(|side| side.unwrap_or(default));
+
+        // `inside`/`outside`, if set, take priority over `left`/`right`
+        // once the page is mirrored for two-sided printing. The content is
+        // only laid out once below, at a single width, and merely
+        // translated sideways per page after that (see the loop below) —
+        // which only works if recto and verso end up with the same total
+        // horizontal margin. That holds whenever `inside`/`outside` are
+        // set as a pair, but if only one of them is set while the plain
+        // `left`/`right` margins are asymmetric, the two parities' totals
+        // can differ. Use whichever parity has the larger total margin for
+        // the actual layout width, so a narrower-margin page can only ever
+        // leave extra space on its wider side, never overflow into it.
+        let two_sided = styles.get(Self::TWO_SIDED);
+        let binding = styles.get(Self::BINDING).unwrap_or_else(|| {
+            if styles.get(TextNode::DIR) == Dir::RTL { Binding::Right } else { Binding::Left }
+        });
+        let inside = styles.get(Self::INSIDE);
+        let outside = styles.get(Self::OUTSIDE);
+        let padding = if two_sided {
+            let recto = mirror(margins, binding, 1, inside, outside, default);
+            let verso = mirror(margins, binding, 2, inside, outside, default);
+            let recto_width = recto.resolve(styles).relative_to(size);
+            let verso_width = verso.resolve(styles).relative_to(size);
+            if recto_width.left + recto_width.right >= verso_width.left + verso_width.right {
+                recto
+            } else {
+                verso
+            }
+        } else {
+            margins
+        };
 
         let mut child = self.0.clone();
 
@@ -107,7 +202,25 @@ impl PageNode {
         // Realize overlays.
         for frame in &mut frames {
             let size = frame.size();
-            let pad = padding.resolve(styles).relative_to(size);
+
+            // Re-resolve the margins for this specific frame's page number:
+            // within a single page run of a two-sided document, recto and
+            // verso pages alternate, so each frame may need `inside`/
+            // `outside` mapped the other way around than the frame before
+            // it. Shift the already-laid-out content sideways to match,
+            // since its width is unaffected (see the comment above).
+            let frame_padding = if two_sided {
+                mirror(margins, binding, page, inside, outside, default)
+            } else {
+                padding
+            };
+            if frame_padding.left != padding.left {
+                let was = padding.resolve(styles).relative_to(size);
+                let now = frame_padding.resolve(styles).relative_to(size);
+                frame.translate(Point::with_x(now.left - was.left));
+            }
+
+            let pad = frame_padding.resolve(styles).relative_to(size);
             let pw = size.x - pad.left - pad.right;
             let py = size.y - pad.bottom;
             for (role, marginal, pos, area) in [
@@ -154,6 +267,112 @@ impl Debug for PageNode {
     }
 }
 
+/// Resolve `inside`/`outside` onto `left`/`right`, based on which physical
+/// side is the "inside" for the given page number, matching the same
+/// 1-indexed numbering handed to header/footer functions via
+/// [`Marginal::resolve`]: the binding is on the left for recto (odd,
+/// right-hand) pages — so the very first page of the document is recto —
+/// and on the right for verso (even, left-hand) pages, or the other way
+/// around if `binding` is [`Binding::Right`].
+fn mirror(
+    mut sides: Sides<Rel<Length>>,
+    binding: Binding,
+    page: usize,
+    inside: Option<Smart<Rel<Length>>>,
+    outside: Option<Smart<Rel<Length>>>,
+    default: Rel<Length>,
+) -> Sides<Rel<Length>> {
+    let recto = page % 2 == 1;
+    let inside_is_left = match binding {
+        Binding::Left => recto,
+        Binding::Right => !recto,
+    };
+
+    if let Some(inside) = inside {
+        let value = inside.unwrap_or(default);
+        if inside_is_left {
+            sides.left = value;
+        } else {
+            sides.right = value;
+        }
+    }
+
+    if let Some(outside) = outside {
+        let value = outside.unwrap_or(default);
+        if inside_is_left {
+            sides.right = value;
+        } else {
+            sides.left = value;
+        }
+    }
+
+    sides
+}
+
+/// A page's orientation, which determines whether `width` and `height` are
+/// swapped once laid out.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Orientation {
+    /// `width` and `height` are used as given. A page set from `paper` is at
+    /// least as tall as it is wide.
+    Portrait,
+    /// `width` and `height` are swapped at layout time, so a page set from
+    /// `paper` is at least as wide as it is tall.
+    Landscape,
+}
+
+castable! {
+    Orientation,
+    Expected: "\"portrait\" or \"landscape\"",
+    Value::Str(string) => match string.as_str() {
+        "portrait" => Self::Portrait,
+        "landscape" => Self::Landscape,
+        _ => Err("expected \"portrait\" or \"landscape\"")?,
+    },
+}
+
+/// Which side of the page the binding/gutter is on, used to resolve
+/// `inside`/`outside` margins onto `left`/`right`.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Binding {
+    /// Bound on the left, as is common for left-to-right books.
+    Left,
+    /// Bound on the right, as is common for right-to-left books.
+    Right,
+}
+
+castable! {
+    Binding,
+    Expected: "\"left\" or \"right\"",
+    Value::Str(string) => match string.as_str() {
+        "left" => Self::Left,
+        "right" => Self::Right,
+        _ => Err("expected \"left\" or \"right\"")?,
+    },
+}
+
+/// The page's `width` or `height`: an explicit length, `auto` to fit the
+/// page to its content along that axis, or (the default) simply unset, in
+/// which case [`PageNode::layout`] asks for the locale's preferred paper
+/// instead. Unlike `auto`, `unset` is never produced by user input — there
+/// is no syntax for "unsetting" a dimension once it has been set.
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub enum PageDimension {
+    /// Not set by the user.
+    Unset,
+    /// Fit the page to its content along this axis.
+    Auto,
+    /// An explicit length.
+    Length(Length),
+}
+
+castable! {
+    PageDimension,
+    Expected: "length or auto",
+    Value::Auto => Self::Auto,
+    Value::Length(v) => Self::Length(v),
+}
+
 /// A page break.
 #[derive(Debug, Copy, Clone, Hash)]
 pub struct PagebreakNode {
@@ -235,16 +454,86 @@ impl Paper {
     pub fn height(self) -> Abs {
         Abs::mm(self.height)
     }
+
+    /// Extract the country portion of a POSIX locale string like
+    /// `en_US.UTF-8` and map the letter-paper-using countries to
+    /// [`US_LETTER`](Self::US_LETTER), everything else to [`A4`](Self::A4).
+    ///
+    /// Pure and deterministic by design: reading the actual locale out of
+    /// the process environment (or glibc's `LC_PAPER` extension) is the
+    /// compiler host's call, not the library's — see
+    /// [`World::default_paper`]. A host that wants a locale-aware default,
+    /// like the CLI's `SystemWorld`, probes its environment itself and
+    /// feeds the result through this function.
+    pub fn country_paper(locale: &str) -> Option<Self> {
+        let country = locale.split(['.', '@']).next()?.split('_').nth(1)?;
+        Some(match country.to_uppercase().as_str() {
+            "US" | "CA" | "MX" | "CL" | "CO" | "VE" | "PH" => Self::US_LETTER,
+            _ => Self::A4,
+        })
+    }
+
+    /// Find the predefined paper nearest to the given dimensions, the way
+    /// LibreOffice's sloppy paper matching works: both the given orientation
+    /// and its landscape swap are tried, a candidate is accepted when each
+    /// dimension is within roughly 6mm, and the closest overall match wins.
+    /// Returns `None` if no predefined paper is close enough.
+    pub fn nearest(width: Abs, height: Abs) -> Option<Self> {
+        const TOLERANCE: f64 = 6.0;
+
+        let w = width.to_mm();
+        let h = height.to_mm();
+
+        let mut best = None;
+        for paper in Self::ALL {
+            for (pw, ph) in [(paper.width, paper.height), (paper.height, paper.width)] {
+                let dw = (pw - w).abs();
+                let dh = (ph - h).abs();
+                if dw > TOLERANCE || dh > TOLERANCE {
+                    continue;
+                }
+
+                let error = dw + dh;
+                if best.map_or(true, |(_, best_error)| error < best_error) {
+                    best = Some((*paper, error));
+                }
+            }
+        }
+
+        best.map(|(paper, _)| paper)
+    }
 }
 
 /// Defines paper constants and a paper parsing implementation.
 macro_rules! papers {
-    ($(($var:ident: $width:expr, $height: expr, $($pats:tt)*))*) => {
+    ($(($var:ident: $width:expr, $height: expr, $name:literal $(| $alias:literal)* $(, ps: $ps:literal)?))*) => {
         /// Predefined papers.
         ///
-        /// Each paper is parsable from its name in kebab-case.
+        /// Each paper is parsable from its name in kebab-case, or from its
+        /// PostScript/PPD name where it has one (see [`Paper::ps_name`]).
         impl Paper {
             $(pub const $var: Self = Self { width: $width, height: $height };)*
+
+            /// All predefined papers.
+            const ALL: &'static [Self] = &[$(Self::$var),*];
+
+            /// The canonical, kebab-case name of the paper.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$var => $name,)*
+                }
+            }
+
+            /// The canonical PostScript/PPD name of the paper (e.g.
+            /// `"Letter"`), as catalogued by LibreOffice's `PageDesc` table.
+            /// Used by PDF/PostScript export to emit the right `PageSize`/
+            /// media name. Falls back to the kebab-case [`Paper::name`] for
+            /// papers without a recognized PPD identifier.
+            pub fn ps_name(self) -> &'static str {
+                match self {
+                    $(Self::$var => papers!(@ps $name $(, $ps)?),)*
+                }
+            }
         }
 
         impl FromStr for Paper {
@@ -252,12 +541,14 @@ macro_rules! papers {
 
             fn from_str(name: &str) -> Result<Self, Self::Err> {
                 match name.to_lowercase().as_str() {
-                    $($($pats)* => Ok(Self::$var),)*
+                    $($name $(| $alias)* => Ok(Self::$var),)*
                     _ => Err("invalid paper name"),
                 }
             }
         }
     };
+    (@ps $name:literal) => { $name };
+    (@ps $name:literal, $ps:literal) => { $ps };
 }
 
 castable! {
@@ -280,7 +571,7 @@ papers! {
     (A1:  594.0,  841.0, "a1")
     (A2:  420.0,  594.0, "a2")
     (A3:  297.0,  420.0, "a3")
-    (A4:  210.0,  297.0, "a4")
+    (A4:  210.0,  297.0, "a4", ps: "A4")
     (A5:  148.0,  210.0, "a5")
     (A6:  105.0,  148.0, "a6")
     (A7:   74.0,  105.0, "a7")
@@ -302,7 +593,7 @@ papers! {
     // ISO 216 C Series
     (ISO_C3: 324.0, 458.0, "iso-c3")
     (ISO_C4: 229.0, 324.0, "iso-c4")
-    (ISO_C5: 162.0, 229.0, "iso-c5")
+    (ISO_C5: 162.0, 229.0, "iso-c5" | "c5", ps: "C5")
     (ISO_C6: 114.0, 162.0, "iso-c6")
     (ISO_C7:  81.0, 114.0, "iso-c7")
     (ISO_C8:  57.0,  81.0, "iso-c8")
@@ -398,9 +689,9 @@ papers! {
     (UK_BOOK_B:   129.0, 198.0, "uk-book-b")
 
     // Unites States
-    (US_LETTER:         215.9,  279.4, "us-letter")
-    (US_LEGAL:          215.9,  355.6, "us-legal")
-    (US_TABLOID:        279.4,  431.8, "us-tabloid")
+    (US_LETTER:         215.9,  279.4, "us-letter" | "letter", ps: "Letter")
+    (US_LEGAL:          215.9,  355.6, "us-legal" | "legal", ps: "Legal")
+    (US_TABLOID:        279.4,  431.8, "us-tabloid" | "tabloid", ps: "Tabloid")
     (US_EXECUTIVE:      84.15,  266.7, "us-executive")
     (US_FOOLSCAP_FOLIO: 215.9,  342.9, "us-foolscap-folio")
     (US_STATEMENT:      139.7,  215.9, "us-statement")
@@ -412,6 +703,11 @@ papers! {
     (US_DIGEST:         139.7,  215.9, "us-digest")
     (US_TRADE:          152.4,  228.6, "us-trade")
 
+    // ---------------------------------------------------------------------- //
+    // Envelopes
+    (ENVELOPE_DL:    110.0,   220.0, "envelope-dl" | "dl", ps: "DL")
+    (ENVELOPE_COMM10: 104.775, 241.3, "envelope-comm10" | "comm10", ps: "Comm10")
+
     // ---------------------------------------------------------------------- //
     // Other
     (NEWSPAPER_COMPACT:    280.0,    430.0,    "newspaper-compact")
@@ -420,3 +716,63 @@ papers! {
     (PRESENTATION_16_9:    297.0, 167.0625, "presentation-16-9")
     (PRESENTATION_4_3:     280.0,    210.0,    "presentation-4-3")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_matches_exact_paper_in_either_orientation() {
+        assert_eq!(Paper::nearest(Abs::mm(210.0), Abs::mm(297.0)).map(Paper::name), Some("a4"));
+        assert_eq!(Paper::nearest(Abs::mm(297.0), Abs::mm(210.0)).map(Paper::name), Some("a4"));
+    }
+
+    #[test]
+    fn nearest_matches_within_tolerance() {
+        assert_eq!(Paper::nearest(Abs::mm(212.0), Abs::mm(294.0)).map(Paper::name), Some("a4"));
+    }
+
+    #[test]
+    fn nearest_returns_none_outside_tolerance() {
+        assert_eq!(Paper::nearest(Abs::mm(210.0), Abs::mm(250.0)), None);
+    }
+
+    #[test]
+    fn country_paper_maps_letter_countries_to_us_letter() {
+        assert_eq!(Paper::country_paper("en_US.UTF-8").map(Paper::name), Some("us-letter"));
+        assert_eq!(Paper::country_paper("fr_CA").map(Paper::name), Some("us-letter"));
+    }
+
+    #[test]
+    fn country_paper_falls_back_to_a4() {
+        assert_eq!(Paper::country_paper("de_DE.UTF-8").map(Paper::name), Some("a4"));
+    }
+
+    #[test]
+    fn country_paper_rejects_locale_without_country() {
+        assert_eq!(Paper::country_paper("C"), None);
+    }
+
+    #[test]
+    fn mirror_first_page_is_recto_with_inside_on_the_binding_side() {
+        let margins = Sides::splat(Rel::from(Abs::mm(20.0)));
+        let default = Rel::from(Abs::mm(20.0));
+        let inside = Some(Smart::Custom(Rel::from(Abs::mm(30.0))));
+
+        // Page 1 is recto, and a left binding puts `inside` on the left.
+        let resolved = mirror(margins, Binding::Left, 1, inside, None, default);
+        assert_eq!(resolved.left, Rel::from(Abs::mm(30.0)));
+        assert_eq!(resolved.right, Rel::from(Abs::mm(20.0)));
+    }
+
+    #[test]
+    fn mirror_alternates_between_recto_and_verso() {
+        let margins = Sides::splat(Rel::from(Abs::mm(20.0)));
+        let default = Rel::from(Abs::mm(20.0));
+        let inside = Some(Smart::Custom(Rel::from(Abs::mm(30.0))));
+
+        let page_2 = mirror(margins, Binding::Left, 2, inside, None, default);
+        assert_eq!(page_2.right, Rel::from(Abs::mm(30.0)));
+        assert_eq!(page_2.left, Rel::from(Abs::mm(20.0)));
+    }
+}
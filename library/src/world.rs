@@ -0,0 +1,22 @@
+use crate::layout::Paper;
+
+/// The environment a document is compiled in.
+///
+/// Implemented by whatever embeds the compiler, such as the CLI's
+/// `SystemWorld` or a web service's per-request sandbox, to supply the
+/// inputs and host policies the compiler itself stays agnostic to.
+pub trait World {
+    /// The paper to default to when a page's `width`/`height` were never
+    /// set at all.
+    ///
+    /// Returns [`Paper::A4`] unless overridden. A host that wants to honor
+    /// the invoking user's locale, like the CLI's `SystemWorld`, overrides
+    /// this to probe its own environment (e.g. `LC_PAPER`/`LANG` via
+    /// `nl_langinfo`) and feed the result through [`Paper::country_paper`].
+    /// The library itself never reads the process environment, so
+    /// compiling the same document twice in one process, as a multi-tenant
+    /// host might, can't silently diverge between callers.
+    fn default_paper(&self) -> Paper {
+        Paper::A4
+    }
+}